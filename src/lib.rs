@@ -46,6 +46,13 @@
 //!     /// * `fn next(&mut self) -> Option<Self::Item>`
 //!     iterator struct RgbCollectionIterator;
 //!
+//!     /// Mutable iterator struct for collection.
+//!     ///
+//!     /// Generated trait method for Iterator:
+//!     ///
+//!     /// * `fn next(&mut self) -> Option<Self::Item>`
+//!     iterator struct mut RgbCollectionIteratorMut;
+//!
 //!     /// A trait for anything that is logically an immutable, shared
 //!     /// reference to an `Rgb`.
 //!     ///
@@ -112,10 +119,117 @@
 //!         #[derive(Debug)]
 //!         ref mut RgbSoaRefMut;
 //!     }
+//!
+//!     aosoa {
+//!         width = 8;
+//!
+//!         /// A tiled array-of-structs-of-arrays representation of many
+//!         /// `Rgb`s, with `8` lanes per tile.
+//!         ///
+//!         /// This is laid out in memory like:
+//!         ///
+//!         ///    ... | r*8 | g*8 | b*8 | r*8 | g*8 | b*8 | ...
+//!         #[derive(Debug)]
+//!         struct RgbAosoa;
+//!
+//!         /// A single tile of `8` `Rgb`s backing a `RgbAosoa`.
+//!         #[derive(Debug)]
+//!         tile struct RgbAosoaTile;
+//!
+//!         /// An immutable, shared reference to an `Rgb` inside of a `RgbAosoa`.
+//!         ///
+//!         /// Implements the `RgbRef` trait.
+//!         #[derive(Debug, Clone, Copy)]
+//!         ref RgbAosoaRef;
+//!
+//!         /// A mutable, unique reference to an `Rgb` inside of a `RgbAosoa`.
+//!         ///
+//!         /// Implements the `RgbRef` and `RgbRefMut` traits.
+//!         #[derive(Debug)]
+//!         ref mut RgbAosoaRefMut;
+//!     }
 //! }
 //!
 //! # fn main() {}
 //! ```
+//!
+//! # `no_std` Support
+//!
+//! With its default `std` feature disabled, this crate is `no_std`, relying
+//! only on `alloc` for its generated `Vec` backed collections. The `std`
+//! feature is on by default.
+//!
+//! # Parallel Iteration
+//!
+//! Enable the `rayon` feature to generate `par_iter`/`par_iter_mut` methods
+//! on the collection trait, backed by splittable rayon producers over the
+//! collection's index range. The generated code checks
+//! `cfg(feature = "rayon")` in the crate that invokes `aossoa!`, so these
+//! methods and types are only generated when that crate has its own `rayon`
+//! feature enabled, and that feature must in turn enable this crate's
+//! `rayon` feature. Because indices are disjoint, `par_iter_mut`
+//! can safely hand out non-overlapping mutable references to worker threads.
+//! `par_iter`/`par_iter_mut` return dedicated parallel-iterator types,
+//! distinct from the plain `Iterator`s returned by `iter`/`iter_mut`: `rayon`'s
+//! `ParallelIterator` and `std`'s `Iterator` both define methods like `map`
+//! and `sum`, so a single type implementing both would make every such call
+//! ambiguous as soon as `ParallelIterator` is in scope.
+//!
+//! # Columnar Reductions
+//!
+//! The collection trait also gets a `reduce_<field>` method per field, which
+//! folds that one field's column with a user-supplied [`Monoid`]. Each
+//! representation folds its own way: SoA walks its field's `Vec`
+//! contiguously, AoS strides through the struct array, and AoSoA accumulates
+//! lane-width partial sums per tile before combining them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+// Not part of the public API. Re-exports `Vec` from whichever of `alloc` or
+// `std` is in play, so that the `aossoa!` macro can name it by an absolute
+// path (`$crate::__private::Vec`) and resolve correctly no matter what
+// crate it's invoked from, `no_std` or otherwise.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "std")]
+    pub use std::vec::Vec;
+
+    #[cfg(not(feature = "std"))]
+    pub use alloc::vec::Vec;
+
+    // Re-exported so the `aossoa!` macro can name rayon's plumbing by an
+    // absolute `$crate::__private::rayon` path, regardless of whether
+    // `rayon` is in scope in the crate the macro is invoked from.
+    #[cfg(feature = "rayon")]
+    pub use rayon;
+
+    // Re-exported so the `aossoa!` macro can build `reduce_<field>` method
+    // names by concatenating identifiers, regardless of whether `paste` is
+    // in scope in the crate the macro is invoked from.
+    pub use paste;
+}
+
+/// An associative binary operation over `Item`, with an identity element.
+///
+/// The `reduce_<field>` methods generated by the `aossoa!` macro fold a
+/// single field column with a `Monoid`, so the implementation is free to
+/// reassociate or chunk the fold (e.g. accumulating lane-width partial sums
+/// before combining them) however best suits the representation.
+pub trait Monoid {
+    /// The type of value this monoid combines.
+    type Item;
+
+    /// The identity element, such that `op(unit(), x) == op(x, unit()) == x`
+    /// for all `x`.
+    fn unit() -> Self::Item;
+
+    /// Combine two values. Must be associative:
+    /// `op(op(a, b), c) == op(a, op(b, c))`.
+    fn op(&self, a: Self::Item, b: Self::Item) -> Self::Item;
+}
 
 #[macro_export]
 macro_rules! aossoa {
@@ -131,6 +245,9 @@ macro_rules! aossoa {
         $( #[$iterator_struct_attr:meta] )*
         iterator struct $iterator_struct_name:ident ;
 
+        $( #[$iterator_struct_mut_attr:meta] )*
+        iterator struct mut $iterator_struct_mut_name:ident ;
+
         $( #[$ref_trait_attr:meta] )*
         ref trait $ref_trait_name:ident ;
 
@@ -159,6 +276,22 @@ macro_rules! aossoa {
             ref mut $soa_ref_mut_name:ident ;
         }
 
+        aosoa {
+            width = $width:literal ;
+
+            $( #[$aosoa_attr:meta] )*
+            struct $aosoa_name:ident ;
+
+            $( #[$aosoa_tile_attr:meta] )*
+            tile struct $aosoa_tile_name:ident ;
+
+            $( #[$aosoa_ref_attr:meta] )*
+            ref $aosoa_ref_name:ident ;
+
+            $( #[$aosoa_ref_mut_attr:meta] )*
+            ref mut $aosoa_ref_mut_name:ident ;
+        }
+
     ) => {
 
         // Struct //////////////////////////////////////////////////////////////
@@ -178,12 +311,6 @@ macro_rules! aossoa {
             /// The associated unique, mutable reference type.
             type Mut: $ref_mut_trait_name;
 
-            // /// TODO FITZGEN
-            // type Iter: Iterator<Item = Self::Ref>;
-
-            // /// TODO FITZGEN
-            // type IterMut: Iterator<Item = Self::Mut>;
-
             /// Construct a new, empty instance of this collection.
             fn new() -> Self {
                 Self::with_capacity(0)
@@ -234,14 +361,65 @@ macro_rules! aossoa {
             fn get_mut(&'a mut self, idx: usize) -> Option<Self::Mut>;
 
             fn iter(&'a self) -> $iterator_struct_name <'a, Self> {
-                $iterator_struct_name::<'a, Self> { collection: &self, index: 0}
+                let end = self.len();
+                $iterator_struct_name::<'a, Self> { collection: &self, index: 0, end }
             }
 
-            // /// TODO FITZGEN
-            // fn iter_mut(&'a mut self) -> Self::IterMut;
-        }
+            /// Get a mutable iterator over all of the items in this
+            /// collection.
+            fn iter_mut(&'a mut self) -> $iterator_struct_mut_name <'a, Self> {
+                let end = self.len();
+                $iterator_struct_mut_name::<'a, Self> {
+                    collection: self as *mut Self,
+                    index: 0,
+                    end,
+                    marker: ::core::marker::PhantomData,
+                }
+            }
+
+            $crate::__private::paste::paste! {
+                /// Get a splittable, work-stealing parallel iterator over
+                /// all of the items in this collection.
+                ///
+                /// Returns a dedicated parallel-iterator type rather than
+                /// the plain [`Iterator`] returned by `iter`: `rayon`'s
+                /// `ParallelIterator` and `std`'s `Iterator` both define
+                /// methods like `map` and `sum`, so a single type
+                /// implementing both would make every such call ambiguous
+                /// as soon as `ParallelIterator` is in scope.
+                #[cfg(feature = "rayon")]
+                fn par_iter(&'a self) -> [<$iterator_struct_name Par>] <'a, Self> {
+                    let end = self.len();
+                    [<$iterator_struct_name Par>] ::<'a, Self> { collection: &self, index: 0, end }
+                }
 
-        // TODO FITZGEN: IntoIterator for &Collection and &mut Collection
+                /// Get a splittable, work-stealing parallel iterator that
+                /// yields non-overlapping mutable references to the items in
+                /// this collection.
+                ///
+                /// See `par_iter` for why this is its own type rather than
+                /// the plain [`Iterator`] returned by `iter_mut`.
+                #[cfg(feature = "rayon")]
+                fn par_iter_mut(&'a mut self) -> [<$iterator_struct_mut_name Par>] <'a, Self> {
+                    let end = self.len();
+                    [<$iterator_struct_mut_name Par>] ::<'a, Self> {
+                        collection: self as *mut Self,
+                        index: 0,
+                        end,
+                        marker: ::core::marker::PhantomData,
+                    }
+                }
+
+                $(
+                    /// Fold the `$field_name` column with the associative
+                    /// operation and identity supplied by `m`, without
+                    /// materializing a full `$name`. Each representation
+                    /// folds however best suits its memory layout.
+                    fn [<reduce_ $field_name>]<M>(&'a self, m: &M) -> M::Item
+                        where M: $crate::Monoid<Item = $field_ty>, $field_ty: Clone;
+                )*
+            }
+        }
 
         $( #[$ref_trait_attr] )*
         pub trait $ref_trait_name {
@@ -264,6 +442,7 @@ macro_rules! aossoa {
         {
             collection: &'a T,
             index: usize,
+            end: usize,
         }
 
         impl<'a, T> Iterator for $iterator_struct_name<'a, T>
@@ -272,6 +451,9 @@ macro_rules! aossoa {
             type Item = T::Ref;
 
             fn next(&mut self) -> Option<Self::Item> {
+                if self.index >= self.end {
+                    return None;
+                }
                 let value = self.collection.get(self.index);
                 // TODO: We could probably reuse the index in the Ref type somehow
                 //       Possibly by making the Ref type the iterator
@@ -280,10 +462,295 @@ macro_rules! aossoa {
             }
         }
 
+        #[cfg(feature = "rayon")]
+        impl<'a, T> ExactSizeIterator for $iterator_struct_name<'a, T>
+            where T: 'a + $collection_trait_name<'a>
+        {
+            fn len(&self) -> usize {
+                self.end - self.index
+            }
+        }
+
+        // `rayon::iter::plumbing::Producer::IntoIter` requires
+        // `DoubleEndedIterator` in addition to `ExactSizeIterator`.
+        #[cfg(feature = "rayon")]
+        impl<'a, T> DoubleEndedIterator for $iterator_struct_name<'a, T>
+            where T: 'a + $collection_trait_name<'a>
+        {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.index >= self.end {
+                    return None;
+                }
+                self.end -= 1;
+                self.collection.get(self.end)
+            }
+        }
+
+        $crate::__private::paste::paste! {
+            // A dedicated parallel-iterator/producer type, returned by
+            // `par_iter`, rather than also implementing `ParallelIterator`
+            // on `$iterator_struct_name` itself: `rayon`'s
+            // `ParallelIterator` and `std`'s `Iterator` both define methods
+            // like `map` and `sum`, so a single type implementing both
+            // would make every such call ambiguous as soon as
+            // `ParallelIterator` is in scope. `Producer::IntoIter` converts
+            // back to the plain `$iterator_struct_name` once rayon stops
+            // splitting the work.
+            #[cfg(feature = "rayon")]
+            pub struct [<$iterator_struct_name Par>]<'a, T>
+                where T: 'a + $collection_trait_name<'a>
+            {
+                collection: &'a T,
+                index: usize,
+                end: usize,
+            }
+
+            #[cfg(feature = "rayon")]
+            impl<'a, T> $crate::__private::rayon::iter::ParallelIterator for [<$iterator_struct_name Par>]<'a, T>
+                where T: 'a + $collection_trait_name<'a> + Sync, T::Ref: Send
+            {
+                type Item = T::Ref;
+
+                fn drive_unindexed<C>(self, consumer: C) -> C::Result
+                    where C: $crate::__private::rayon::iter::plumbing::UnindexedConsumer<Self::Item>
+                {
+                    $crate::__private::rayon::iter::plumbing::bridge(self, consumer)
+                }
+
+                fn opt_len(&self) -> Option<usize> {
+                    Some(self.end - self.index)
+                }
+            }
+
+            #[cfg(feature = "rayon")]
+            impl<'a, T> $crate::__private::rayon::iter::IndexedParallelIterator for [<$iterator_struct_name Par>]<'a, T>
+                where T: 'a + $collection_trait_name<'a> + Sync, T::Ref: Send
+            {
+                fn len(&self) -> usize {
+                    self.end - self.index
+                }
+
+                fn drive<C>(self, consumer: C) -> C::Result
+                    where C: $crate::__private::rayon::iter::plumbing::Consumer<Self::Item>
+                {
+                    $crate::__private::rayon::iter::plumbing::bridge(self, consumer)
+                }
+
+                fn with_producer<CB>(self, callback: CB) -> CB::Output
+                    where CB: $crate::__private::rayon::iter::plumbing::ProducerCallback<Self::Item>
+                {
+                    callback.callback(self)
+                }
+            }
+
+            #[cfg(feature = "rayon")]
+            impl<'a, T> $crate::__private::rayon::iter::plumbing::Producer for [<$iterator_struct_name Par>]<'a, T>
+                where T: 'a + $collection_trait_name<'a> + Sync, T::Ref: Send
+            {
+                type Item = T::Ref;
+                type IntoIter = $iterator_struct_name<'a, T>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    $iterator_struct_name { collection: self.collection, index: self.index, end: self.end }
+                }
+
+                fn split_at(self, index: usize) -> (Self, Self) {
+                    let mid = self.index + index;
+                    (
+                        [<$iterator_struct_name Par>] { collection: self.collection, index: self.index, end: mid },
+                        [<$iterator_struct_name Par>] { collection: self.collection, index: mid, end: self.end },
+                    )
+                }
+            }
+        }
+
+        $( #[$iterator_struct_mut_attr] )*
+        pub struct $iterator_struct_mut_name<'a, T>
+            where T: 'a + $collection_trait_name<'a>
+        {
+            // A raw pointer rather than a live `&'a mut T`, matching
+            // `[<$iterator_struct_mut_name Par>]`'s representation (see
+            // below) so `par_iter_mut`'s `Producer::into_iter` can hand
+            // this type the same pointer without ever materializing two
+            // live `&mut T`. The only `&'a mut T` ever constructed here is
+            // a transient, per-call reborrow in `next`/`next_back`, and
+            // each such reborrow touches a single, never-repeated index.
+            collection: *mut T,
+            index: usize,
+            end: usize,
+            marker: ::core::marker::PhantomData<&'a mut T>,
+        }
+
+        impl<'a, T> Iterator for $iterator_struct_mut_name<'a, T>
+            where T: 'a + $collection_trait_name<'a>
+        {
+            type Item = T::Mut;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.index >= self.end {
+                    return None;
+                }
+
+                let idx = self.index;
+                self.index += 1;
+
+                // SAFETY: each call to `next` hands out a `Self::Mut` for a
+                // strictly increasing, and therefore never-repeated, index.
+                // The indices are disjoint, so reborrowing the `collection`
+                // pointer here for the iterator's lifetime `'a` cannot alias
+                // a previously yielded reference.
+                let collection: &'a mut T = unsafe { &mut *self.collection };
+                collection.get_mut(idx)
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl<'a, T> ExactSizeIterator for $iterator_struct_mut_name<'a, T>
+            where T: 'a + $collection_trait_name<'a>
+        {
+            fn len(&self) -> usize {
+                self.end - self.index
+            }
+        }
+
+        // `rayon::iter::plumbing::Producer::IntoIter` requires
+        // `DoubleEndedIterator` in addition to `ExactSizeIterator`.
+        #[cfg(feature = "rayon")]
+        impl<'a, T> DoubleEndedIterator for $iterator_struct_mut_name<'a, T>
+            where T: 'a + $collection_trait_name<'a>
+        {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.index >= self.end {
+                    return None;
+                }
+                self.end -= 1;
+                let idx = self.end;
+                // SAFETY: see the matching comment in `next` above; `idx` is
+                // never repeated since `end` only ever shrinks past it.
+                let collection: &'a mut T = unsafe { &mut *self.collection };
+                collection.get_mut(idx)
+            }
+        }
+
+        $crate::__private::paste::paste! {
+            // A dedicated parallel-iterator/producer type, returned by
+            // `par_iter_mut`, rather than also implementing
+            // `ParallelIterator` on `$iterator_struct_mut_name` itself; see
+            // the matching comment on `[<$iterator_struct_name Par>]`
+            // above for why.
+            #[cfg(feature = "rayon")]
+            pub struct [<$iterator_struct_mut_name Par>]<'a, T>
+                where T: 'a + $collection_trait_name<'a>
+            {
+                // Same raw-pointer-plus-marker representation as
+                // `$iterator_struct_mut_name`, and for the same reason:
+                // copying this pointer into a split producer's two halves
+                // never creates two simultaneously-live `&mut T` to the
+                // whole collection.
+                collection: *mut T,
+                index: usize,
+                end: usize,
+                marker: ::core::marker::PhantomData<&'a mut T>,
+            }
+
+            // The raw `*mut T` field would otherwise make this type
+            // `!Send`. This is sound because the pointer is only ever
+            // dereferenced transiently, one never-repeated index at a
+            // time, once converted to a sequential iterator via
+            // `Producer::into_iter`.
+            #[cfg(feature = "rayon")]
+            unsafe impl<'a, T> Send for [<$iterator_struct_mut_name Par>]<'a, T>
+                where T: 'a + $collection_trait_name<'a> + Send, T::Mut: Send
+            {}
+
+            #[cfg(feature = "rayon")]
+            impl<'a, T> $crate::__private::rayon::iter::ParallelIterator for [<$iterator_struct_mut_name Par>]<'a, T>
+                where T: 'a + $collection_trait_name<'a> + Send, T::Mut: Send
+            {
+                type Item = T::Mut;
+
+                fn drive_unindexed<C>(self, consumer: C) -> C::Result
+                    where C: $crate::__private::rayon::iter::plumbing::UnindexedConsumer<Self::Item>
+                {
+                    $crate::__private::rayon::iter::plumbing::bridge(self, consumer)
+                }
+
+                fn opt_len(&self) -> Option<usize> {
+                    Some(self.end - self.index)
+                }
+            }
+
+            #[cfg(feature = "rayon")]
+            impl<'a, T> $crate::__private::rayon::iter::IndexedParallelIterator for [<$iterator_struct_mut_name Par>]<'a, T>
+                where T: 'a + $collection_trait_name<'a> + Send, T::Mut: Send
+            {
+                fn len(&self) -> usize {
+                    self.end - self.index
+                }
+
+                fn drive<C>(self, consumer: C) -> C::Result
+                    where C: $crate::__private::rayon::iter::plumbing::Consumer<Self::Item>
+                {
+                    $crate::__private::rayon::iter::plumbing::bridge(self, consumer)
+                }
+
+                fn with_producer<CB>(self, callback: CB) -> CB::Output
+                    where CB: $crate::__private::rayon::iter::plumbing::ProducerCallback<Self::Item>
+                {
+                    callback.callback(self)
+                }
+            }
+
+            #[cfg(feature = "rayon")]
+            impl<'a, T> $crate::__private::rayon::iter::plumbing::Producer for [<$iterator_struct_mut_name Par>]<'a, T>
+                where T: 'a + $collection_trait_name<'a> + Send, T::Mut: Send
+            {
+                type Item = T::Mut;
+                type IntoIter = $iterator_struct_mut_name<'a, T>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    $iterator_struct_mut_name {
+                        collection: self.collection,
+                        index: self.index,
+                        end: self.end,
+                        marker: ::core::marker::PhantomData,
+                    }
+                }
+
+                // Splitting copies the raw `collection` pointer into both
+                // halves, with disjoint, non-overlapping `[index, end)`
+                // ranges. Copying a raw pointer constructs no `&mut T` at
+                // all — the pointer itself carries no exclusivity
+                // guarantee — so this never has two live `&'a mut T` to the
+                // whole collection at once, even though both halves may run
+                // concurrently on separate threads. Each half only ever
+                // transiently reborrows the pointer inside
+                // `next`/`next_back`, for a single index that belongs to it
+                // alone, once converted via `into_iter`.
+                fn split_at(self, index: usize) -> (Self, Self) {
+                    let mid = self.index + index;
+                    (
+                        [<$iterator_struct_mut_name Par>] {
+                            collection: self.collection,
+                            index: self.index,
+                            end: mid,
+                            marker: ::core::marker::PhantomData,
+                        },
+                        [<$iterator_struct_mut_name Par>] {
+                            collection: self.collection,
+                            index: mid,
+                            end: self.end,
+                            marker: ::core::marker::PhantomData,
+                        },
+                    )
+                }
+            }
+        }
+
         // AOS /////////////////////////////////////////////////////////////////
 
         $( #[$aos_attr] )*
-        pub struct $aos_name(Vec<$name>);
+        pub struct $aos_name($crate::__private::Vec<$name>);
 
         impl<'a> $collection_trait_name<'a> for $aos_name {
             type Ref = $aos_ref_name<'a>;
@@ -293,7 +760,7 @@ macro_rules! aossoa {
             // type IterMut: Iterator<Item = Self::Mut>;
 
             fn with_capacity(capacity: usize) -> Self {
-                $aos_name(Vec::with_capacity(capacity))
+                $aos_name($crate::__private::Vec::with_capacity(capacity))
             }
 
             fn capacity(&self) -> usize {
@@ -321,21 +788,27 @@ macro_rules! aossoa {
             }
 
             fn get(&'a self, idx: usize) -> Option<Self::Ref> {
-                self.0.get(idx).map(|r| $aos_ref_name { r: r })
+                self.0.get(idx).map(|r| $aos_ref_name { r })
             }
 
             fn get_mut(&'a mut self, idx: usize) -> Option<Self::Mut> {
-                self.0.get_mut(idx).map(|r| $aos_ref_mut_name { r: r })
+                self.0.get_mut(idx).map(|r| $aos_ref_mut_name { r })
             }
 
-            // /// TODO FITZGEN
-            // fn iter(&'a self) -> Self::Iter;
-
-            // /// TODO FITZGEN
-            // fn iter_mut(&'a mut self) -> Self::IterMut;
+            $crate::__private::paste::paste! {
+                $(
+                    fn [<reduce_ $field_name>]<M>(&'a self, m: &M) -> M::Item
+                        where M: $crate::Monoid<Item = $field_ty>, $field_ty: Clone
+                    {
+                        // Strides through the struct array, since the
+                        // `$field_name` column isn't stored contiguously here.
+                        self.0.iter().fold(M::unit(), |acc, item| m.op(acc, item.$field_name.clone()))
+                    }
+                )*
+            }
         }
 
-        impl ::std::iter::FromIterator<$name> for $aos_name
+        impl ::core::iter::FromIterator<$name> for $aos_name
         {
             fn from_iter<I>(iter: I) -> Self
                 where I: IntoIterator<Item = $name>
@@ -348,6 +821,24 @@ macro_rules! aossoa {
             }
         }
 
+        impl<'a> IntoIterator for &'a $aos_name {
+            type Item = <$aos_name as $collection_trait_name<'a>>::Ref;
+            type IntoIter = $iterator_struct_name<'a, $aos_name>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $aos_name {
+            type Item = <$aos_name as $collection_trait_name<'a>>::Mut;
+            type IntoIter = $iterator_struct_mut_name<'a, $aos_name>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
         $( #[$aos_ref_attr] )*
         pub struct $aos_ref_name <'a> {
             r: &'a $name
@@ -386,7 +877,7 @@ macro_rules! aossoa {
 
         $( #[$soa_attr] )*
         pub struct $soa_name {
-            $( $field_name : Vec<$field_ty> , )*
+            $( $field_name : $crate::__private::Vec<$field_ty> , )*
         }
 
         impl<'a> $collection_trait_name<'a> for $soa_name {
@@ -398,7 +889,7 @@ macro_rules! aossoa {
 
             fn with_capacity(capacity: usize) -> Self {
                 $soa_name {
-                    $( $field_name: Vec::with_capacity(capacity), )*
+                    $( $field_name: $crate::__private::Vec::with_capacity(capacity), )*
                 }
             }
 
@@ -457,7 +948,7 @@ macro_rules! aossoa {
 
                 Some($soa_ref_name {
                     soa: self,
-                    idx: idx,
+                    idx,
                 })
             }
 
@@ -466,20 +957,35 @@ macro_rules! aossoa {
                     return None;
                 }
 
+                // Narrow to a raw pointer into each field's element at
+                // `idx`, rather than capturing `&'a mut self`: the latter
+                // would let every `Self::Mut` yielded by `iter_mut`/
+                // `par_iter_mut` alias the whole collection, even though
+                // each only ever touches its own disjoint `idx`. The
+                // `&mut self.$field_name[idx]` borrows below are disjoint
+                // fields of `self`, so the borrow checker accepts all of
+                // them in the same literal.
                 Some($soa_ref_mut_name {
-                    soa: self,
-                    idx: idx,
+                    $( $field_name: &mut self.$field_name[idx] as *mut $field_ty, )*
+                    marker: ::core::marker::PhantomData,
                 })
             }
 
-            // /// TODO FITZGEN
-            // fn iter(&'a self) -> Self::Iter;
-
-            // /// TODO FITZGEN
-            // fn iter_mut(&'a mut self) -> Self::IterMut;
+            $crate::__private::paste::paste! {
+                $(
+                    fn [<reduce_ $field_name>]<M>(&'a self, m: &M) -> M::Item
+                        where M: $crate::Monoid<Item = $field_ty>, $field_ty: Clone
+                    {
+                        // The `$field_name` column is a contiguous `Vec`
+                        // here, which is exactly the memory access pattern
+                        // SoA is meant to make fast.
+                        self.$field_name.iter().fold(M::unit(), |acc, v| m.op(acc, v.clone()))
+                    }
+                )*
+            }
         }
 
-        impl ::std::iter::FromIterator<$name> for $soa_name
+        impl ::core::iter::FromIterator<$name> for $soa_name
         {
             fn from_iter<I>(iter: I) -> Self
                 where I: IntoIterator<Item = $name>
@@ -492,6 +998,24 @@ macro_rules! aossoa {
             }
         }
 
+        impl<'a> IntoIterator for &'a $soa_name {
+            type Item = <$soa_name as $collection_trait_name<'a>>::Ref;
+            type IntoIter = $iterator_struct_name<'a, $soa_name>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $soa_name {
+            type Item = <$soa_name as $collection_trait_name<'a>>::Mut;
+            type IntoIter = $iterator_struct_mut_name<'a, $soa_name>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
         $( #[$soa_ref_attr] )*
         pub struct $soa_ref_name<'a> {
             soa: &'a $soa_name,
@@ -508,14 +1032,31 @@ macro_rules! aossoa {
 
         $( #[$soa_ref_mut_attr] )*
         pub struct $soa_ref_mut_name<'a> {
-            soa: &'a mut $soa_name,
-            idx: usize,
+            // A raw pointer per field, narrowed to the single element at
+            // `idx`, rather than `soa: &'a mut $soa_name`: holding the
+            // whole collection would make every `Self::Mut` yielded by
+            // `iter_mut`/`par_iter_mut` alias one another, since they'd
+            // all carry a live `&mut $soa_name` to the same object. Each
+            // pointer here instead points only at the one element this
+            // value was handed out for, so distinct `idx`s never alias.
+            $( $field_name: *mut $field_ty, )*
+            marker: ::core::marker::PhantomData<&'a mut $soa_name>,
         }
 
+        // The raw `*mut _` fields would otherwise make this type `!Send`.
+        // This is sound for the same reason as the collection's own mutable
+        // iterator types: each pointer is narrowed to a single, never
+        // aliased element, so handing one off to another thread can't
+        // create overlapping exclusive access.
+        #[cfg(feature = "rayon")]
+        unsafe impl<'a> Send for $soa_ref_mut_name<'a>
+            where $( $field_ty: Send, )*
+        {}
+
         impl<'a> $ref_trait_name for $soa_ref_mut_name<'a> {
             $(
                 fn $field_name (&self) -> & $field_ty {
-                    &self.soa. $field_name [self.idx]
+                    unsafe { & *self.$field_name }
                 }
             )*
         }
@@ -523,7 +1064,256 @@ macro_rules! aossoa {
         impl<'a> $ref_mut_trait_name for $soa_ref_mut_name<'a> {
             $(
                 fn $field_name (&mut self) -> &mut $field_ty {
-                    &mut self.soa. $field_name [self.idx]
+                    unsafe { &mut *self.$field_name }
+                }
+            )*
+        }
+
+        // AOSOA ///////////////////////////////////////////////////////////////
+
+        $( #[$aosoa_tile_attr] )*
+        struct $aosoa_tile_name {
+            $( $field_name : [$field_ty; $width] , )*
+        }
+
+        impl $aosoa_tile_name {
+            fn new() -> Self
+                where $( $field_ty: Default , )*
+            {
+                $aosoa_tile_name {
+                    $( $field_name: ::core::array::from_fn(|_| <$field_ty>::default()), )*
+                }
+            }
+        }
+
+        $( #[$aosoa_attr] )*
+        pub struct $aosoa_name {
+            tiles: $crate::__private::Vec<$aosoa_tile_name>,
+            len: usize,
+        }
+
+        impl<'a> $collection_trait_name<'a> for $aosoa_name
+            where $( $field_ty: Default , )*
+        {
+            type Ref = $aosoa_ref_name<'a>;
+            type Mut = $aosoa_ref_mut_name<'a>;
+
+            fn with_capacity(capacity: usize) -> Self {
+                $aosoa_name {
+                    tiles: $crate::__private::Vec::with_capacity(capacity.div_ceil($width)),
+                    len: 0,
+                }
+            }
+
+            fn capacity(&self) -> usize {
+                self.tiles.capacity() * $width
+            }
+
+            fn reserve(&mut self, additional: usize) {
+                let needed_tiles = (self.len + additional).div_ceil($width);
+                if needed_tiles > self.tiles.len() {
+                    self.tiles.reserve(needed_tiles - self.tiles.len());
+                }
+            }
+
+            fn truncate(&mut self, len: usize) {
+                if len >= self.len {
+                    return;
+                }
+                self.tiles.truncate(len.div_ceil($width));
+                let lane = len % $width;
+                if lane != 0 {
+                    // The tail tile is still retained for its live lanes
+                    // `[0, lane)`; drop the now-out-of-bounds lanes
+                    // `[lane, WIDTH)` promptly instead of leaving them
+                    // aliased until a later `push` or the tile's own drop.
+                    if let Some(tile) = self.tiles.last_mut() {
+                        $(
+                            for l in lane..$width {
+                                tile.$field_name[l] = Default::default();
+                            }
+                        )*
+                    }
+                }
+                self.len = len;
+            }
+
+            fn push(&mut self, value: $name) {
+                let tile_idx = self.len / $width;
+                let lane = self.len % $width;
+                if lane == 0 {
+                    self.tiles.push($aosoa_tile_name::new());
+                }
+                let tile = &mut self.tiles[tile_idx];
+                $(
+                    tile.$field_name [lane] = value.$field_name;
+                )*
+                self.len += 1;
+            }
+
+            fn pop(&mut self) -> Option<$name> {
+                if self.len == 0 {
+                    return None;
+                }
+                self.len -= 1;
+                let tile_idx = self.len / $width;
+                let lane = self.len % $width;
+                let tile = &mut self.tiles[tile_idx];
+                let value = $name {
+                    $( $field_name: ::core::mem::replace(&mut tile.$field_name [lane], Default::default()), )*
+                };
+                if lane == 0 {
+                    self.tiles.truncate(tile_idx);
+                }
+                Some(value)
+            }
+
+            fn len(&self) -> usize {
+                self.len
+            }
+
+            fn get(&'a self, idx: usize) -> Option<Self::Ref> {
+                if idx >= self.len {
+                    return None;
+                }
+
+                Some($aosoa_ref_name {
+                    aosoa: self,
+                    idx,
+                })
+            }
+
+            fn get_mut(&'a mut self, idx: usize) -> Option<Self::Mut> {
+                if idx >= self.len {
+                    return None;
+                }
+
+                // Narrow to a raw pointer into each field's lane within the
+                // one tile that holds `idx`, rather than capturing
+                // `&'a mut self`: see the matching comment on
+                // `$soa_name::get_mut` for why. The `&mut tile.$field_name`
+                // borrows below are disjoint fields of the same `tile`
+                // reference, so the borrow checker accepts all of them in
+                // the same literal.
+                let tile_idx = idx / $width;
+                let lane = idx % $width;
+                let tile = &mut self.tiles[tile_idx];
+                Some($aosoa_ref_mut_name {
+                    $( $field_name: &mut tile.$field_name[lane] as *mut $field_ty, )*
+                    marker: ::core::marker::PhantomData,
+                })
+            }
+
+            $crate::__private::paste::paste! {
+                $(
+                    fn [<reduce_ $field_name>]<M>(&'a self, m: &M) -> M::Item
+                        where M: $crate::Monoid<Item = $field_ty>, $field_ty: Clone
+                    {
+                        // Reassociate the fold into lane-width partial sums
+                        // per tile, then combine the partial sums, setting
+                        // up for future SIMD/parallel reductions.
+                        let mut acc = M::unit();
+                        let full_tiles = self.len / $width;
+
+                        for tile in &self.tiles[..full_tiles] {
+                            let mut partial = M::unit();
+                            for lane in 0 .. $width {
+                                partial = m.op(partial, tile.$field_name[lane].clone());
+                            }
+                            acc = m.op(acc, partial);
+                        }
+
+                        let remainder = self.len % $width;
+                        if remainder > 0 {
+                            let tile = &self.tiles[full_tiles];
+                            let mut partial = M::unit();
+                            for lane in 0 .. remainder {
+                                partial = m.op(partial, tile.$field_name[lane].clone());
+                            }
+                            acc = m.op(acc, partial);
+                        }
+
+                        acc
+                    }
+                )*
+            }
+        }
+
+        impl ::core::iter::FromIterator<$name> for $aosoa_name
+            where $( $field_ty: Default , )*
+        {
+            fn from_iter<I>(iter: I) -> Self
+                where I: IntoIterator<Item = $name>
+            {
+                let mut me = Self::new();
+                for x in iter {
+                    me.push(x);
+                }
+                me
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $aosoa_name {
+            type Item = <$aosoa_name as $collection_trait_name<'a>>::Ref;
+            type IntoIter = $iterator_struct_name<'a, $aosoa_name>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $aosoa_name {
+            type Item = <$aosoa_name as $collection_trait_name<'a>>::Mut;
+            type IntoIter = $iterator_struct_mut_name<'a, $aosoa_name>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        $( #[$aosoa_ref_attr] )*
+        pub struct $aosoa_ref_name<'a> {
+            aosoa: &'a $aosoa_name,
+            idx: usize,
+        }
+
+        impl<'a> $ref_trait_name for $aosoa_ref_name<'a> {
+            $(
+                fn $field_name (&self) -> & $field_ty {
+                    &self.aosoa.tiles[self.idx / $width]. $field_name [self.idx % $width]
+                }
+            )*
+        }
+
+        $( #[$aosoa_ref_mut_attr] )*
+        pub struct $aosoa_ref_mut_name<'a> {
+            // A raw pointer per field, narrowed to the single lane this
+            // value was handed out for, rather than
+            // `aosoa: &'a mut $aosoa_name`; see the matching comment on
+            // `$soa_ref_mut_name` for why.
+            $( $field_name: *mut $field_ty, )*
+            marker: ::core::marker::PhantomData<&'a mut $aosoa_name>,
+        }
+
+        // See the matching `Send` impl on `$soa_ref_mut_name` for why this
+        // is sound.
+        #[cfg(feature = "rayon")]
+        unsafe impl<'a> Send for $aosoa_ref_mut_name<'a>
+            where $( $field_ty: Send, )*
+        {}
+
+        impl<'a> $ref_trait_name for $aosoa_ref_mut_name<'a> {
+            $(
+                fn $field_name (&self) -> & $field_ty {
+                    unsafe { & *self.$field_name }
+                }
+            )*
+        }
+
+        impl<'a> $ref_mut_trait_name for $aosoa_ref_mut_name<'a> {
+            $(
+                fn $field_name (&mut self) -> &mut $field_ty {
+                    unsafe { &mut *self.$field_name }
                 }
             )*
         }
@@ -532,7 +1322,11 @@ macro_rules! aossoa {
 
 #[cfg(test)]
 mod tests {
-    use std::iter::FromIterator;
+    // `core::iter::FromIterator` (not `std::iter::FromIterator`) so this
+    // module also compiles under `cargo test --no-default-features`, where
+    // the crate is `#![no_std]` and `std` isn't in scope.
+    use core::iter::FromIterator;
+    use crate::Monoid;
 
     aossoa!{
         #[derive(Clone, Copy, Debug)]
@@ -544,6 +1338,7 @@ mod tests {
 
         collection trait RgbCollection;
         iterator struct RgbCollectionIterator;
+        iterator struct mut RgbCollectionIteratorMut;
         ref trait RgbRef;
         ref mut trait RgbRefMut;
 
@@ -558,6 +1353,14 @@ mod tests {
             ref RgbSoaRef;
             ref mut RgbSoaRefMut;
         }
+
+        aosoa {
+            width = 2;
+            struct RgbAosoa;
+            tile struct RgbAosoaTile;
+            ref RgbAosoaRef;
+            ref mut RgbAosoaRefMut;
+        }
     }
 
     fn sum_all_rgb<'a, T: RgbCollection<'a>>(rgbs: &'a T) -> usize {
@@ -582,6 +1385,26 @@ mod tests {
         sum
     }
 
+    fn sum_all_rgb_into_iter<'a, T>(rgbs: &'a T) -> usize
+        where T: RgbCollection<'a>,
+              &'a T: IntoIterator<Item = T::Ref>,
+    {
+        let mut sum = 0;
+        for rgb in rgbs {
+            sum += *rgb.r() as usize;
+            sum += *rgb.g() as usize;
+            sum += *rgb.b() as usize;
+        }
+        sum
+    }
+
+    fn double_all_rgb<'a, T: RgbCollection<'a>>(rgbs: &'a mut T) {
+        for mut rgb in rgbs.iter_mut() {
+            let r = *RgbRef::r(&rgb);
+            *RgbRefMut::r(&mut rgb) = r.wrapping_mul(2);
+        }
+    }
+
     #[test]
     fn sum_all_rgb_test() {
         let aos = RgbAos::from_iter([
@@ -602,9 +1425,148 @@ mod tests {
             Rgb { r: 1, g: 2, b: 3 },
         ].iter().cloned());
 
+        let aosoa = RgbAosoa::from_iter([
+            Rgb { r: 1, g: 2, b: 3 },
+            Rgb { r: 1, g: 2, b: 3 },
+            Rgb { r: 1, g: 2, b: 3 },
+            Rgb { r: 1, g: 2, b: 3 },
+            Rgb { r: 1, g: 2, b: 3 },
+            Rgb { r: 1, g: 2, b: 3 },
+        ].iter().cloned());
+
         assert_eq!(sum_all_rgb(&aos), 36);
         assert_eq!(sum_all_rgb(&soa), 36);
+        assert_eq!(sum_all_rgb(&aosoa), 36);
         assert_eq!(sum_all_rgb_iter(&aos), 36);
         assert_eq!(sum_all_rgb_iter(&soa), 36);
+        assert_eq!(sum_all_rgb_iter(&aosoa), 36);
+        assert_eq!(sum_all_rgb_into_iter(&aos), 36);
+        assert_eq!(sum_all_rgb_into_iter(&soa), 36);
+        assert_eq!(sum_all_rgb_into_iter(&aosoa), 36);
+    }
+
+    struct SumU8;
+
+    impl Monoid for SumU8 {
+        type Item = u8;
+
+        fn unit() -> u8 {
+            0
+        }
+
+        fn op(&self, a: u8, b: u8) -> u8 {
+            a + b
+        }
+    }
+
+    #[test]
+    fn reduce_field_test() {
+        let m = SumU8;
+
+        let empty_aos = RgbAos::new();
+        let empty_soa = RgbSoa::new();
+        let empty_aosoa = RgbAosoa::new();
+        assert_eq!(empty_aos.reduce_r(&m), 0);
+        assert_eq!(empty_soa.reduce_r(&m), 0);
+        assert_eq!(empty_aosoa.reduce_r(&m), 0);
+
+        let rgbs = [
+            Rgb { r: 1, g: 10, b: 20 },
+            Rgb { r: 2, g: 20, b: 21 },
+            Rgb { r: 3, g: 30, b: 22 },
+            Rgb { r: 4, g: 40, b: 23 },
+            Rgb { r: 5, g: 50, b: 24 },
+        ];
+
+        let aos = RgbAos::from_iter(rgbs.iter().cloned());
+        let soa = RgbSoa::from_iter(rgbs.iter().cloned());
+        let aosoa = RgbAosoa::from_iter(rgbs.iter().cloned());
+
+        assert_eq!(aos.reduce_r(&m), 15);
+        assert_eq!(soa.reduce_r(&m), 15);
+        assert_eq!(aosoa.reduce_r(&m), 15);
+        assert_eq!(aos.reduce_g(&m), 150);
+        assert_eq!(soa.reduce_g(&m), 150);
+        assert_eq!(aosoa.reduce_g(&m), 150);
+
+        // `RgbAosoa` has a lane width of 2, so 5 elements straddle a tile
+        // boundary: 2 full tiles plus a partial tail tile of 1 lane. Make
+        // sure the partial tile's accumulation isn't off by one.
+        assert_eq!(aosoa.reduce_b(&m), 20 + 21 + 22 + 23 + 24);
+    }
+
+    #[test]
+    fn iter_mut_test() {
+        let mut aos = RgbAos::from_iter([
+            Rgb { r: 1, g: 2, b: 3 },
+            Rgb { r: 1, g: 2, b: 3 },
+        ].iter().cloned());
+
+        let mut soa = RgbSoa::from_iter([
+            Rgb { r: 1, g: 2, b: 3 },
+            Rgb { r: 1, g: 2, b: 3 },
+        ].iter().cloned());
+
+        let mut aosoa = RgbAosoa::from_iter([
+            Rgb { r: 1, g: 2, b: 3 },
+            Rgb { r: 1, g: 2, b: 3 },
+        ].iter().cloned());
+
+        double_all_rgb(&mut aos);
+        double_all_rgb(&mut soa);
+        double_all_rgb(&mut aosoa);
+
+        assert_eq!(sum_all_rgb(&aos), 14);
+        assert_eq!(sum_all_rgb(&soa), 14);
+        assert_eq!(sum_all_rgb(&aosoa), 14);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter_test() {
+        use rayon::iter::ParallelIterator;
+
+        let elems = (0..64).map(|i| Rgb { r: i as u8, g: 2, b: 3 });
+        let aos = RgbAos::from_iter(elems.clone());
+        let soa = RgbSoa::from_iter(elems.clone());
+        let aosoa = RgbAosoa::from_iter(elems);
+
+        let expected: usize = (0..64usize).sum();
+
+        assert_eq!(aos.par_iter().map(|r| *r.r() as usize).sum::<usize>(), expected);
+        assert_eq!(soa.par_iter().map(|r| *r.r() as usize).sum::<usize>(), expected);
+        assert_eq!(aosoa.par_iter().map(|r| *r.r() as usize).sum::<usize>(), expected);
+    }
+
+    // Exercises `Producer::split_at` for the mutable parallel iterator
+    // across a large enough collection that rayon's work-stealing scheduler
+    // actually splits it and runs both halves concurrently, rather than
+    // just sequentially draining a single unsplit producer.
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter_mut_test() {
+        use rayon::iter::ParallelIterator;
+
+        let mut aos = RgbAos::from_iter((0..256).map(|_| Rgb { r: 1, g: 2, b: 3 }));
+        let mut soa = RgbSoa::from_iter((0..256).map(|_| Rgb { r: 1, g: 2, b: 3 }));
+        let mut aosoa = RgbAosoa::from_iter((0..256).map(|_| Rgb { r: 1, g: 2, b: 3 }));
+
+        aos.par_iter_mut().for_each(|mut rgb| {
+            let r = *RgbRef::r(&rgb);
+            *RgbRefMut::r(&mut rgb) = r.wrapping_mul(2);
+        });
+        soa.par_iter_mut().for_each(|mut rgb| {
+            let r = *RgbRef::r(&rgb);
+            *RgbRefMut::r(&mut rgb) = r.wrapping_mul(2);
+        });
+        aosoa.par_iter_mut().for_each(|mut rgb| {
+            let r = *RgbRef::r(&rgb);
+            *RgbRefMut::r(&mut rgb) = r.wrapping_mul(2);
+        });
+
+        assert_eq!(sum_all_rgb(&aos), 256 * (2 + 2 + 3));
+        assert_eq!(sum_all_rgb(&soa), 256 * (2 + 2 + 3));
+        assert_eq!(sum_all_rgb(&aosoa), 256 * (2 + 2 + 3));
     }
 }
+